@@ -0,0 +1,308 @@
+use crate::buf::Chain;
+
+#[cfg(feature = "std")]
+use crate::buf::{Reader, Take};
+
+#[cfg(feature = "std")]
+use std::io::IoSlice;
+
+/// Read bytes from a buffer.
+///
+/// A buffer stores bytes in memory such that read operations are infallible.
+/// The underlying storage may or may not be in contiguous memory. A `Buf`
+/// value is a cursor into the buffer. Reading bytes from the value advances
+/// the cursor position. It can be thought of as an efficient `Iterator` for
+/// collections of bytes.
+///
+/// The simplest `Buf` is a `&[u8]`.
+///
+/// ```
+/// use bytes::Buf;
+///
+/// let mut buf = &b"hello world"[..];
+///
+/// assert_eq!(b'h', buf.bytes()[0]);
+///
+/// buf.advance(1);
+///
+/// assert_eq!(b'e', buf.bytes()[0]);
+/// ```
+pub trait Buf {
+    /// Returns the number of bytes between the current position and the end
+    /// of the buffer.
+    ///
+    /// This value is greater than or equal to the length of the slice
+    /// returned by `bytes`.
+    fn remaining(&self) -> usize;
+
+    /// Returns a slice starting at the current position and of length
+    /// between 0 and `Buf::remaining()`. Note that this *can* return shorter
+    /// slice (this allows non-continuous internal representation).
+    fn bytes(&self) -> &[u8];
+
+    /// Advance the internal cursor of the buffer.
+    ///
+    /// The next call to `bytes` will return a slice starting `cnt` bytes
+    /// further into the underlying buffer.
+    ///
+    /// # Panics
+    ///
+    /// This function **may** panic if `cnt > self.remaining()`.
+    fn advance(&mut self, cnt: usize);
+
+    /// Returns true if there are any more bytes to consume.
+    ///
+    /// This is equivalent to `self.remaining() != 0`.
+    fn has_remaining(&self) -> bool {
+        self.remaining() > 0
+    }
+
+    /// Fills `dst` with potentially multiple slices starting at `self`'s
+    /// current position.
+    ///
+    /// The return value is the number of slices filled. The default
+    /// implementation fills a single slice with `bytes()`.
+    #[cfg(feature = "std")]
+    fn bytes_vectored<'a>(&'a self, dst: &mut [IoSlice<'a>]) -> usize {
+        if dst.is_empty() || !self.has_remaining() {
+            return 0;
+        }
+
+        dst[0] = IoSlice::new(self.bytes());
+        1
+    }
+
+    /// Copies bytes from `self` into `dst`, advancing `self` by the number
+    /// of bytes copied.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `self.remaining() < dst.len()`.
+    fn copy_to_slice(&mut self, dst: &mut [u8]) {
+        assert!(self.remaining() >= dst.len());
+
+        let mut off = 0;
+
+        while off < dst.len() {
+            let cnt;
+
+            unsafe {
+                let src = self.bytes();
+                cnt = std::cmp::min(src.len(), dst.len() - off);
+
+                std::ptr::copy_nonoverlapping(src.as_ptr(), dst[off..].as_mut_ptr(), cnt);
+
+                off += cnt;
+            }
+
+            self.advance(cnt);
+        }
+    }
+
+    /// Consumes `self`, returning the remaining bytes as a contiguous
+    /// [`Bytes`].
+    ///
+    /// [`Bytes`]: crate::Bytes
+    fn to_bytes(&mut self) -> crate::Bytes {
+        let mut ret = crate::BytesMut::with_capacity(self.remaining());
+
+        while self.has_remaining() {
+            let len = {
+                let slice = self.bytes();
+                ret.extend_from_slice(slice);
+                slice.len()
+            };
+
+            self.advance(len);
+        }
+
+        ret.freeze()
+    }
+
+    /// Creates an adapter which will chain this buffer with another.
+    ///
+    /// The returned `Buf` instance will first consume all bytes from
+    /// `self`, then consume all bytes from `next`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytes::Buf;
+    ///
+    /// let mut chain = b"hello "[..].chain(&b"world"[..]);
+    ///
+    /// let full = chain.to_bytes();
+    /// assert_eq!(full[..], b"hello world"[..]);
+    /// ```
+    fn chain<U: Buf>(self, next: U) -> Chain<Self, U>
+    where
+        Self: Sized,
+    {
+        Chain::new(self, next)
+    }
+
+    /// Creates an adapter which will read at most `limit` bytes from `self`.
+    #[cfg(feature = "std")]
+    fn take(self, limit: usize) -> Take<Self>
+    where
+        Self: Sized,
+    {
+        super::take::new(self, limit)
+    }
+
+    /// Creates an adapter which implements the `Read` trait for `self`.
+    #[cfg(feature = "std")]
+    fn reader(self) -> Reader<Self>
+    where
+        Self: Sized,
+    {
+        super::reader::new(self)
+    }
+
+    /// Compares the remaining bytes of this buffer to `other` for equality,
+    /// without first collapsing either side into a contiguous allocation.
+    ///
+    /// This walks a cheap clone of `self` segment-by-segment, so it never
+    /// copies the underlying bytes even when `self` is made up of multiple
+    /// chunks (e.g. a [`Chain`] or [`BufList`]).
+    ///
+    /// [`Chain`]: crate::buf::Chain
+    /// [`BufList`]: crate::buf::BufList
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytes::Buf;
+    ///
+    /// let buf = b"hello "[..].chain(&b"world"[..]);
+    /// assert!(buf.bytes_eq(b"hello world"));
+    /// ```
+    fn bytes_eq(&self, other: &[u8]) -> bool
+    where
+        Self: Clone,
+    {
+        if self.remaining() != other.len() {
+            return false;
+        }
+
+        let mut cursor = self.clone();
+        let mut off = 0;
+
+        while cursor.has_remaining() {
+            let chunk = cursor.bytes();
+            let n = chunk.len();
+
+            if chunk != &other[off..off + n] {
+                return false;
+            }
+
+            cursor.advance(n);
+            off += n;
+        }
+
+        true
+    }
+
+    /// Returns the offset of the first occurrence of `needle`, or `None` if
+    /// it does not occur.
+    ///
+    /// `needle` is matched across segment boundaries by carrying the
+    /// in-progress match state from one segment into the next, so a
+    /// delimiter split across two chained segments is still found without
+    /// first collapsing the buffer into a contiguous allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytes::Buf;
+    ///
+    /// let buf = b"hel"[..].chain(&b"lo world"[..]);
+    /// assert_eq!(buf.find(b"lo"), Some(3));
+    /// ```
+    fn find(&self, needle: &[u8]) -> Option<usize>
+    where
+        Self: Clone,
+    {
+        if needle.is_empty() {
+            return Some(0);
+        }
+
+        // Knuth-Morris-Pratt failure function: `lps[i]` is the length of
+        // the longest proper prefix of `needle[..=i]` that is also a
+        // suffix of it. This lets a mismatch fall back to the longest
+        // partial match still in progress, instead of restarting from
+        // scratch, so needles with a self-overlapping prefix/suffix (e.g.
+        // `b"aab"`) are still found correctly.
+        let mut lps = vec![0usize; needle.len()];
+        let mut prefix_len = 0;
+        let mut i = 1;
+        while i < needle.len() {
+            if needle[i] == needle[prefix_len] {
+                prefix_len += 1;
+                lps[i] = prefix_len;
+                i += 1;
+            } else if prefix_len != 0 {
+                prefix_len = lps[prefix_len - 1];
+            } else {
+                i += 1;
+            }
+        }
+
+        let mut cursor = self.clone();
+        let mut pos = 0;
+        let mut matched = 0;
+
+        while cursor.has_remaining() {
+            let chunk = cursor.bytes();
+            let chunk_len = chunk.len();
+
+            for &byte in chunk {
+                while matched > 0 && byte != needle[matched] {
+                    matched = lps[matched - 1];
+                }
+
+                if byte == needle[matched] {
+                    matched += 1;
+                }
+
+                pos += 1;
+
+                if matched == needle.len() {
+                    return Some(pos - needle.len());
+                }
+            }
+
+            cursor.advance(chunk_len);
+        }
+
+        None
+    }
+}
+
+impl<T: Buf + ?Sized> Buf for &mut T {
+    fn remaining(&self) -> usize {
+        (**self).remaining()
+    }
+
+    fn bytes(&self) -> &[u8] {
+        (**self).bytes()
+    }
+
+    fn advance(&mut self, cnt: usize) {
+        (**self).advance(cnt)
+    }
+}
+
+impl Buf for &[u8] {
+    fn remaining(&self) -> usize {
+        self.len()
+    }
+
+    fn bytes(&self) -> &[u8] {
+        self
+    }
+
+    fn advance(&mut self, cnt: usize) {
+        *self = &self[cnt..];
+    }
+}
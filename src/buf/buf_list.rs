@@ -0,0 +1,154 @@
+use crate::Buf;
+use std::collections::VecDeque;
+
+#[cfg(feature = "std")]
+use std::io::IoSlice;
+
+/// An n-ary rope of [`Buf`] segments, stored as a flat, growable `VecDeque`.
+///
+/// `BufList` is an alternative to nesting [`Chain`] values. Chaining `N`
+/// buffers with `Chain` produces a type with `O(N)` levels of nesting, and
+/// both `remaining()` and `advance()` on the result cost `O(N)` per call as
+/// they recurse through every level. `BufList` instead keeps its segments in
+/// a single `VecDeque`, so new segments can be appended in amortized `O(1)`
+/// time with [`push_back`] and [`push_front`], and `advance` only ever pops
+/// fully-consumed segments off the front.
+///
+/// This is useful for incremental framing, where new segments arrive one at
+/// a time and need to be read back as a single contiguous [`Buf`].
+///
+/// [`Buf`]: trait.Buf.html
+/// [`Chain`]: struct.Chain.html
+/// [`push_back`]: #method.push_back
+/// [`push_front`]: #method.push_front
+///
+/// # Examples
+///
+/// ```
+/// use bytes::Buf;
+/// use bytes::buf::BufList;
+/// use bytes::Bytes;
+///
+/// let mut list = BufList::new();
+/// list.push_back(Bytes::from(&b"hello "[..]));
+/// list.push_back(Bytes::from(&b"world"[..]));
+///
+/// let full: Bytes = list.to_bytes();
+/// assert_eq!(full[..], b"hello world"[..]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BufList<T> {
+    bufs: VecDeque<T>,
+}
+
+impl<T> Default for BufList<T> {
+    fn default() -> Self {
+        BufList {
+            bufs: VecDeque::new(),
+        }
+    }
+}
+
+impl<T: Buf> BufList<T> {
+    /// Creates a new, empty `BufList`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytes::buf::BufList;
+    /// use bytes::Bytes;
+    ///
+    /// let list: BufList<Bytes> = BufList::new();
+    /// ```
+    pub fn new() -> BufList<T> {
+        BufList {
+            bufs: VecDeque::new(),
+        }
+    }
+
+    /// Appends a buffer segment to the back of the list.
+    ///
+    /// Segments with no remaining bytes are dropped rather than stored, so
+    /// `bytes()` and `advance()` never have to skip over empty segments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytes::Buf;
+    /// use bytes::buf::BufList;
+    /// use bytes::Bytes;
+    ///
+    /// let mut list = BufList::new();
+    /// list.push_back(Bytes::from(&b"hello"[..]));
+    /// assert_eq!(list.remaining(), 5);
+    /// ```
+    pub fn push_back(&mut self, buf: T) {
+        if buf.has_remaining() {
+            self.bufs.push_back(buf);
+        }
+    }
+
+    /// Prepends a buffer segment to the front of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytes::Buf;
+    /// use bytes::buf::BufList;
+    /// use bytes::Bytes;
+    ///
+    /// let mut list = BufList::new();
+    /// list.push_back(Bytes::from(&b"world"[..]));
+    /// list.push_front(Bytes::from(&b"hello "[..]));
+    ///
+    /// let full: Bytes = list.to_bytes();
+    /// assert_eq!(full[..], b"hello world"[..]);
+    /// ```
+    pub fn push_front(&mut self, buf: T) {
+        if buf.has_remaining() {
+            self.bufs.push_front(buf);
+        }
+    }
+}
+
+impl<T: Buf> Buf for BufList<T> {
+    fn remaining(&self) -> usize {
+        self.bufs.iter().map(Buf::remaining).sum()
+    }
+
+    fn bytes(&self) -> &[u8] {
+        self.bufs.front().map_or(&[][..], Buf::bytes)
+    }
+
+    fn advance(&mut self, mut cnt: usize) {
+        while cnt > 0 {
+            let front_rem = match self.bufs.front() {
+                Some(buf) => buf.remaining(),
+                None => panic!("cannot advance past the end of BufList"),
+            };
+
+            if front_rem > cnt {
+                self.bufs.front_mut().unwrap().advance(cnt);
+                return;
+            }
+
+            self.bufs.pop_front();
+            cnt -= front_rem;
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn bytes_vectored<'a>(&'a self, dst: &mut [IoSlice<'a>]) -> usize {
+        let mut n = 0;
+
+        for buf in &self.bufs {
+            if n == dst.len() {
+                break;
+            }
+
+            n += buf.bytes_vectored(&mut dst[n..]);
+        }
+
+        n
+    }
+}
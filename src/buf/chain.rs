@@ -1,8 +1,9 @@
 use crate::{Buf, BufMut};
-use crate::buf::IntoIter;
+use crate::buf::{IntoIter, SplitTo};
 
 #[cfg(feature = "std")]
 use std::io::{IoSlice, IoSliceMut};
+use std::mem;
 
 /// A `Chain` sequences two buffers.
 ///
@@ -29,7 +30,7 @@ use std::io::{IoSlice, IoSliceMut};
 /// [`Buf::chain`]: trait.Buf.html#method.chain
 /// [`Buf`]: trait.Buf.html
 /// [`BufMut`]: trait.BufMut.html
-#[derive(Debug)]
+#[derive(Debug, Clone, Default)]
 pub struct Chain<T, U> {
     a: T,
     b: U,
@@ -146,6 +147,66 @@ impl<T, U> Chain<T, U> {
     }
 }
 
+impl<T, U> Chain<T, U>
+where
+    T: Buf + SplitTo + Default,
+    U: Buf + SplitTo + Default,
+{
+    /// Splits the buffer into two at the given index.
+    ///
+    /// Afterwards `self` contains the bytes `[at, self.remaining())`, and
+    /// the returned `Chain` contains the bytes `[0, at)`. If `at` falls
+    /// inside `self.a`, only `a` is split and `b` is left untouched; if it
+    /// falls inside `self.b`, all of `a` moves into the returned `Chain`
+    /// alongside a split of `b`. Either way, no bytes are copied: the split
+    /// happens inside whichever underlying segment `at` lands in, via that
+    /// segment's own [`SplitTo`] implementation.
+    ///
+    /// [`SplitTo`]: trait.SplitTo.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > self.remaining()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytes::{Bytes, Buf};
+    ///
+    /// let mut buf = Bytes::from(&b"hello "[..])
+    ///             .chain(Bytes::from(&b"world"[..]));
+    ///
+    /// let mut head = buf.split_to(8);
+    ///
+    /// assert_eq!(head.to_bytes()[..], b"hello wo"[..]);
+    /// assert_eq!(buf.to_bytes()[..], b"rld"[..]);
+    /// ```
+    pub fn split_to(&mut self, at: usize) -> Chain<T, U> {
+        assert!(at <= self.remaining(), "split_to out of bounds");
+
+        let a_rem = self.a.remaining();
+
+        if at <= a_rem {
+            let a_head = self.a.split_to(at);
+            Chain::new(a_head, U::default())
+        } else {
+            let a_head = mem::take(&mut self.a);
+            let b_head = self.b.split_to(at - a_rem);
+            Chain::new(a_head, b_head)
+        }
+    }
+}
+
+impl<T, U> SplitTo for Chain<T, U>
+where
+    T: Buf + SplitTo + Default,
+    U: Buf + SplitTo + Default,
+{
+    fn split_to(&mut self, at: usize) -> Self {
+        Chain::split_to(self, at)
+    }
+}
+
 impl<T, U> Buf for Chain<T, U>
     where T: Buf,
           U: Buf,
@@ -17,9 +17,11 @@
 //! [`BufMut`]: trait.BufMut.html
 
 mod buf_impl;
+mod buf_list;
 mod buf_mut;
 mod chain;
 mod iter;
+mod split;
 mod take;
 mod vec_deque;
 
@@ -30,11 +32,13 @@ mod reader;
 mod writer;
 
 pub use self::buf_impl::Buf;
+pub use self::buf_list::BufList;
 pub use self::buf_mut::BufMut;
 pub use self::chain::Chain;
 pub use self::iter::IntoIter;
 #[cfg(feature = "std")]
 pub use self::reader::Reader;
+pub use self::split::SplitTo;
 pub use self::take::Take;
 #[cfg(feature = "std")]
 pub use self::writer::Writer;
@@ -0,0 +1,35 @@
+/// Types that can be split into two owned pieces at a byte offset without
+/// copying.
+///
+/// This is implemented by buffer types backed by shareable storage, such as
+/// [`Bytes`] and [`BytesMut`], both of which already expose an inherent
+/// `split_to` that slices their underlying storage instead of copying it.
+/// Adapters like [`Chain`] use this trait to offer a zero-copy `split_to` of
+/// their own whenever both of their underlying buffers support it.
+///
+/// [`Bytes`]: crate::Bytes
+/// [`BytesMut`]: crate::BytesMut
+/// [`Chain`]: crate::buf::Chain
+pub trait SplitTo: Sized {
+    /// Splits the buffer into two at the given index.
+    ///
+    /// Afterwards `self` contains the elements `[at, len)`, and the
+    /// returned value contains the elements `[0, at)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > self.remaining()`.
+    fn split_to(&mut self, at: usize) -> Self;
+}
+
+impl SplitTo for crate::Bytes {
+    fn split_to(&mut self, at: usize) -> Self {
+        crate::Bytes::split_to(self, at)
+    }
+}
+
+impl SplitTo for crate::BytesMut {
+    fn split_to(&mut self, at: usize) -> Self {
+        crate::BytesMut::split_to(self, at)
+    }
+}
@@ -0,0 +1,52 @@
+#![deny(warnings, rust_2018_idioms)]
+
+use bytes::Buf;
+
+#[test]
+fn bytes_eq_single_segment() {
+    let buf = &b"hello world"[..];
+    assert!(buf.bytes_eq(b"hello world"));
+    assert!(!buf.bytes_eq(b"hello there"));
+    assert!(!buf.bytes_eq(b"hello"));
+}
+
+#[test]
+fn bytes_eq_across_chain_boundary() {
+    let buf = b"hello "[..].chain(&b"world"[..]);
+    assert!(buf.bytes_eq(b"hello world"));
+    assert!(!buf.bytes_eq(b"hello there"));
+}
+
+#[test]
+fn find_within_single_segment() {
+    let buf = &b"hello world"[..];
+    assert_eq!(buf.find(b"world"), Some(6));
+    assert_eq!(buf.find(b"xyz"), None);
+    assert_eq!(buf.find(b""), Some(0));
+}
+
+#[test]
+fn find_straddling_chain_boundary() {
+    let buf = b"hel"[..].chain(&b"lo world"[..]);
+    assert_eq!(buf.find(b"hello"), Some(0));
+    assert_eq!(buf.find(b"lo"), Some(3));
+    assert_eq!(buf.find(b"world"), Some(6));
+}
+
+#[test]
+fn find_crlf_split_across_segments() {
+    let buf = b"GET / HTTP/1.1\r"[..].chain(&b"\nHost: example\r\n"[..]);
+    assert_eq!(buf.find(b"\r\n"), Some(14));
+}
+
+#[test]
+fn find_with_self_overlapping_needle() {
+    let buf = &b"aaab"[..];
+    assert_eq!(buf.find(b"aab"), Some(1));
+
+    let buf = &b"0000000"[..];
+    assert_eq!(buf.find(b"0000"), Some(0));
+
+    let buf = &b"ababababc"[..];
+    assert_eq!(buf.find(b"abababc"), Some(2));
+}
@@ -0,0 +1,66 @@
+#![deny(warnings, rust_2018_idioms)]
+
+use bytes::{Buf, Bytes};
+use bytes::buf::BufList;
+use std::io::IoSlice;
+
+#[test]
+fn collect_many_bufs() {
+    let mut list = BufList::new();
+    list.push_back(Bytes::from(&b"hello"[..]));
+    list.push_back(Bytes::from(&b" "[..]));
+    list.push_back(Bytes::from(&b"world"[..]));
+
+    let res = list.to_bytes();
+    assert_eq!(res, &b"hello world"[..]);
+}
+
+#[test]
+fn push_front_prepends_segment() {
+    let mut list = BufList::new();
+    list.push_back(Bytes::from(&b"world"[..]));
+    list.push_front(Bytes::from(&b"hello "[..]));
+
+    let res = list.to_bytes();
+    assert_eq!(res, &b"hello world"[..]);
+}
+
+#[test]
+fn empty_segments_are_not_stored() {
+    let mut list: BufList<Bytes> = BufList::new();
+    list.push_back(Bytes::new());
+    list.push_back(Bytes::from(&b"hello"[..]));
+    list.push_front(Bytes::new());
+
+    assert_eq!(list.remaining(), 5);
+}
+
+#[test]
+fn advance_pops_fully_consumed_segments() {
+    let mut list = BufList::new();
+    list.push_back(Bytes::from(&b"hello"[..]));
+    list.push_back(Bytes::from(&b" "[..]));
+    list.push_back(Bytes::from(&b"world"[..]));
+
+    list.advance(6);
+
+    assert_eq!(list.remaining(), 5);
+    assert_eq!(list.bytes(), &b"world"[..]);
+}
+
+#[test]
+fn vectored_read() {
+    let mut list = BufList::new();
+    list.push_back(Bytes::from(&b"hello"[..]));
+    list.push_back(Bytes::from(&b"world"[..]));
+
+    let b1: &[u8] = &mut [];
+    let b2: &[u8] = &mut [];
+    let b3: &[u8] = &mut [];
+    let mut iovecs = [IoSlice::new(b1), IoSlice::new(b2), IoSlice::new(b3)];
+
+    assert_eq!(2, list.bytes_vectored(&mut iovecs));
+    assert_eq!(iovecs[0][..], b"hello"[..]);
+    assert_eq!(iovecs[1][..], b"world"[..]);
+    assert_eq!(iovecs[2][..], b""[..]);
+}
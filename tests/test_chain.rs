@@ -134,3 +134,59 @@ fn vectored_read() {
         assert_eq!(iovecs[3][..], b""[..]);
     }
 }
+
+#[test]
+fn split_to_within_first() {
+    let mut buf = Bytes::from(&b"hello"[..]).chain(Bytes::from(&b"world"[..]));
+
+    let mut head = buf.split_to(3);
+
+    assert_eq!(head.to_bytes()[..], b"hel"[..]);
+    assert_eq!(buf.to_bytes()[..], b"loworld"[..]);
+}
+
+#[test]
+fn split_to_at_first_boundary() {
+    let mut buf = Bytes::from(&b"hello"[..]).chain(Bytes::from(&b"world"[..]));
+
+    let mut head = buf.split_to(5);
+
+    assert_eq!(head.to_bytes()[..], b"hello"[..]);
+    assert_eq!(buf.to_bytes()[..], b"world"[..]);
+}
+
+#[test]
+fn split_to_within_second() {
+    let mut buf = Bytes::from(&b"hello"[..]).chain(Bytes::from(&b"world"[..]));
+
+    let mut head = buf.split_to(8);
+
+    assert_eq!(head.to_bytes()[..], b"hellowor"[..]);
+    assert_eq!(buf.to_bytes()[..], b"ld"[..]);
+    assert_eq!(buf.remaining(), 2);
+}
+
+#[test]
+fn split_to_everything() {
+    let mut buf = Bytes::from(&b"hello"[..]).chain(Bytes::from(&b"world"[..]));
+
+    let mut head = buf.split_to(10);
+
+    assert_eq!(head.to_bytes()[..], b"helloworld"[..]);
+    assert!(!buf.has_remaining());
+}
+
+#[test]
+fn split_to_on_nested_chain() {
+    use bytes::buf::SplitTo;
+
+    // Chain<Chain<Bytes, Bytes>, Bytes>, i.e. three chained segments.
+    let mut buf = Bytes::from(&b"hel"[..])
+        .chain(Bytes::from(&b"lo "[..]))
+        .chain(Bytes::from(&b"world"[..]));
+
+    let mut head = SplitTo::split_to(&mut buf, 7);
+
+    assert_eq!(head.to_bytes()[..], b"hello w"[..]);
+    assert_eq!(buf.to_bytes()[..], b"orld"[..]);
+}